@@ -3,6 +3,7 @@
 #[cfg(all(not(x11_platform), not(wayland_platform)))]
 compile_error!("Please select a feature to build for unix: `x11`, `wayland`");
 
+use std::cell::OnceCell;
 use std::env;
 use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
 use std::time::Duration;
@@ -179,19 +180,53 @@ impl AsRawFd for EventLoop {
     }
 }
 
-#[cfg(target_os = "linux")]
+thread_local! {
+    static IS_MAIN_THREAD: OnceCell<bool> = const { OnceCell::new() };
+}
+
+/// Whether the calling thread is the process' main thread.
+///
+/// The answer never changes for a given thread, so it's computed once per thread.
 fn is_main_thread() -> bool {
+    IS_MAIN_THREAD.with(|is_main| *is_main.get_or_init(is_main_thread_uncached))
+}
+
+#[cfg(target_os = "linux")]
+fn is_main_thread_uncached() -> bool {
     rustix::thread::gettid() == rustix::process::getpid()
 }
 
 #[cfg(any(target_os = "dragonfly", target_os = "freebsd", target_os = "openbsd"))]
-fn is_main_thread() -> bool {
+fn is_main_thread_uncached() -> bool {
     use libc::pthread_main_np;
 
     unsafe { pthread_main_np() == 1 }
 }
 
 #[cfg(target_os = "netbsd")]
-fn is_main_thread() -> bool {
+fn is_main_thread_uncached() -> bool {
     std::thread::current().name() == Some("main")
 }
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn is_main_thread_is_cached_per_thread() {
+        assert_eq!(is_main_thread(), is_main_thread_uncached());
+        assert!(IS_MAIN_THREAD.with(|cached| cached.get().is_some()));
+        assert_eq!(is_main_thread(), is_main_thread());
+
+        let spawned = thread::spawn(|| {
+            assert!(IS_MAIN_THREAD.with(|cached| cached.get().is_none()));
+            let first = is_main_thread();
+            assert_eq!(IS_MAIN_THREAD.with(|cached| cached.get().copied()), Some(first));
+            assert_eq!(first, is_main_thread());
+            first
+        });
+        assert!(!spawned.join().unwrap());
+    }
+}